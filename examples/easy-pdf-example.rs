@@ -1,16 +1,24 @@
 // SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
 // SPDX-License-Identifier: MIT OR Apache-2.0
 
-use easy_pdf::hello_world;
+use easy_pdf::quick::simple_document;
 
 fn main() {
     env_logger::init();
     if let Err(e) = example() {
         eprintln!("Error: {}", e);
         std::process::exit(1);
-    }   
+    }
 }
 
 fn example() -> Result<(), Box<dyn std::error::Error>> {
-    hello_world()
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "easy-pdf-example.pdf".to_string());
+    let pdf = simple_document(
+        "Hello, world!",
+        &["This document was created with easy-pdf."],
+    )?;
+    std::fs::write(path, pdf)?;
+    Ok(())
 }
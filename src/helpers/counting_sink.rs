@@ -0,0 +1,50 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::io::Write;
+
+/// A [`Write`] sink that discards everything written to it while counting the bytes.
+///
+/// Useful for measuring the size of rendered output without keeping it in memory.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CountingSink {
+    count: usize,
+}
+
+impl CountingSink {
+    /// Creates a new sink with a count of zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total number of bytes written to this sink.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl Write for CountingSink {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.count += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_sink() {
+        let rendered = "<< /Type /Catalog /Pages 2 0 R >>";
+        let mut sink = CountingSink::new();
+        assert_eq!(sink.count(), 0);
+        write!(sink, "{}", rendered).unwrap();
+        sink.write_all(b"\n").unwrap();
+        assert_eq!(sink.count(), rendered.len() + 1);
+    }
+}
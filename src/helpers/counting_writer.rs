@@ -0,0 +1,75 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::io::Write;
+
+/// A [`Write`] adapter that tracks how many bytes have been written through it.
+///
+/// The count is the current position in the output, which is what cross-reference table offsets
+/// need, so callers can read it right before writing an object instead of adding up byte counts
+/// by hand.
+#[derive(Debug)]
+pub struct CountingWriter<W: Write> {
+    inner: W,
+    bytes_written: usize,
+}
+
+impl<W: Write> CountingWriter<W> {
+    /// Creates a new counting writer over `inner`, starting at zero.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            bytes_written: 0,
+        }
+    }
+
+    /// Returns the total number of bytes written so far.
+    pub fn bytes_written(&self) -> usize {
+        self.bytes_written
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Consumes the counting writer, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for CountingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.bytes_written += n;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting_writer_offsets() {
+        let mut writer = CountingWriter::new(Vec::new());
+        writer.write_all(b"%PDF-1.4\n").unwrap();
+        let mut offsets = Vec::new();
+        for id in 1..=3 {
+            // extra bytes between objects must not throw off the offsets
+            writer.write_all(&b"% padding\n".repeat(id)).unwrap();
+            offsets.push(writer.bytes_written());
+            write!(writer, "{} 0 obj\n<<  >>\nendobj\n", id).unwrap();
+        }
+        let data = writer.into_inner();
+        for (i, offset) in offsets.into_iter().enumerate() {
+            let header = format!("{} 0 obj", i + 1);
+            assert!(data[offset..].starts_with(header.as_bytes()));
+        }
+    }
+}
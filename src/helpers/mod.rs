@@ -0,0 +1,12 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Helpers used while writing PDF output.
+
+mod counting_sink;
+mod counting_writer;
+mod tee_writer;
+
+pub use counting_sink::CountingSink;
+pub use counting_writer::CountingWriter;
+pub use tee_writer::TeeWriter;
@@ -0,0 +1,179 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::io::Write;
+
+/// A [`Write`] adapter that forwards every write to two underlying writers.
+///
+/// Bytes are first written to `first`, and only the bytes it accepted are then written to
+/// `second`, so a short write is reported as the number of bytes `first` took. If `second` fails
+/// part way, the bytes it missed are kept and sent to it before anything else is written, so a
+/// caller that retries after an error does not write them to `first` twice.
+///
+/// Bytes still held for `second` are written by [`flush`](Write::flush); call it before
+/// [`into_inner`](Self::into_inner) to make sure both writers hold the same bytes.
+#[derive(Debug)]
+pub struct TeeWriter<W1: Write, W2: Write> {
+    first: W1,
+    second: W2,
+    pending: Vec<u8>,
+}
+
+impl<W1: Write, W2: Write> TeeWriter<W1, W2> {
+    /// Creates a new tee over `first` and `second`.
+    pub fn new(first: W1, second: W2) -> Self {
+        Self {
+            first,
+            second,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Returns references to the underlying writers.
+    pub fn get_ref(&self) -> (&W1, &W2) {
+        (&self.first, &self.second)
+    }
+
+    /// Consumes the tee, returning the underlying writers.
+    ///
+    /// Bytes not yet written to `second` are discarded.
+    pub fn into_inner(self) -> (W1, W2) {
+        (self.first, self.second)
+    }
+
+    /// Writes `buf` to `second`, keeping whatever it did not accept in `pending`.
+    fn write_second(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        let mut written = 0;
+        let result = loop {
+            if written == buf.len() {
+                break Ok(());
+            }
+            match self.second.write(&buf[written..]) {
+                Ok(0) => break Err(std::io::ErrorKind::WriteZero.into()),
+                Ok(n) => written += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.pending.extend_from_slice(&buf[written..]);
+        result
+    }
+
+    /// Writes the bytes `second` missed earlier.
+    fn write_pending(&mut self) -> std::io::Result<()> {
+        let pending = std::mem::take(&mut self.pending);
+        self.write_second(&pending)
+    }
+}
+
+impl<W1: Write, W2: Write> Write for TeeWriter<W1, W2> {
+    /// Writes `buf` to both writers, returning an error without consuming any bytes if bytes
+    /// missed by `second` earlier still cannot be written. Once `first` accepts bytes, a failure
+    /// to write them to `second` is reported by the next call instead.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_pending()?;
+        let n = self.first.write(buf)?;
+        // the bytes are accepted either way; anything left over is written by the next call
+        let _ = self.write_second(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.write_pending()?;
+        self.first.flush()?;
+        self.second.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::CountingSink;
+
+    /// Writer that accepts at most `limit` bytes per call.
+    struct ShortWriter {
+        data: Vec<u8>,
+        limit: usize,
+    }
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            let n = buf.len().min(self.limit);
+            self.data.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_tee_writer() {
+        let mut tee = TeeWriter::new(Vec::new(), CountingSink::new());
+        tee.write_all(b"%PDF-1.4\n").unwrap();
+        tee.write_all(b"%%EOF\n").unwrap();
+        tee.flush().unwrap();
+        let (data, sink) = tee.into_inner();
+        assert_eq!(data, b"%PDF-1.4\n%%EOF\n");
+        assert_eq!(data.len(), sink.count());
+    }
+
+    #[test]
+    fn test_tee_writer_short_write() {
+        let mut tee = TeeWriter::new(
+            ShortWriter {
+                data: Vec::new(),
+                limit: 3,
+            },
+            Vec::new(),
+        );
+        assert_eq!(tee.write(b"abcdefg").unwrap(), 3);
+        tee.write_all(b"defg").unwrap();
+        let (short, data) = tee.into_inner();
+        assert_eq!(short.data, b"abcdefg");
+        assert_eq!(data, b"abcdefg");
+    }
+
+    /// Writer that fails with `WouldBlock` on the given calls.
+    struct FlakyWriter {
+        data: Vec<u8>,
+        calls: usize,
+        failures: Vec<usize>,
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            if self.failures.contains(&self.calls) {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_tee_writer_second_fails() {
+        let flaky = FlakyWriter {
+            data: Vec::new(),
+            calls: 0,
+            failures: vec![1, 2],
+        };
+        let mut tee = TeeWriter::new(Vec::new(), flaky);
+        // first takes the bytes; second's failure is held back
+        assert_eq!(tee.write(b"abc").unwrap(), 3);
+        // the missed bytes still cannot be written, so nothing is consumed
+        let err = tee.write(b"def").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WouldBlock);
+        tee.write_all(b"def").unwrap();
+        tee.flush().unwrap();
+        let (first, second) = tee.into_inner();
+        assert_eq!(first, b"abcdef");
+        assert_eq!(second.data, b"abcdef");
+    }
+}
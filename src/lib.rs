@@ -12,19 +12,27 @@ Run `cargo add easy-pdf` to add the latest version of the `easy-pdf` crate to yo
 
 This example shows how to use the `easy-pdf` crate.
 
-```rust
-use easy_pdf::hello_world;
+```rust,no_run
+use easy_pdf::quick::simple_document;
 
 fn main() {
     env_logger::init();
     if let Err(e) = example() {
         eprintln!("Error: {}", e);
         std::process::exit(1);
-    }   
+    }
 }
 
 fn example() -> Result<(), Box<dyn std::error::Error>> {
-    hello_world()
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "easy-pdf-example.pdf".to_string());
+    let pdf = simple_document(
+        "Hello, world!",
+        &["This document was created with easy-pdf."],
+    )?;
+    std::fs::write(path, pdf)?;
+    Ok(())
 }
 ```
 
@@ -33,11 +41,16 @@ The above example can be run like so:
 ```ignore
 $ git clone https://github.com/wyzzarz/easy-pdf.git
 $ cd easy-pdf
-$ cargo run --example easy-pdf-rs-example
+$ cargo run --example easy-pdf-example -- hello.pdf
 ```
 
 */
 
+pub mod helpers;
+pub mod pdf_object;
+pub mod quick;
+
+#[deprecated(note = "use `quick::simple_document` to create a PDF")]
 pub fn hello_world() -> Result<(), Box<dyn std::error::Error>> {
     eprintln!("Hello, world!");
     Ok(())
@@ -52,6 +65,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(deprecated)]
     fn test_hello_world() {
         assert!(hello_world().is_ok());
     }
@@ -0,0 +1,265 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use std::io::Write;
+
+/// Default column at which ASCII85 output is wrapped.
+const ASCII85_LINE_WIDTH: usize = 80;
+
+/// A [`Write`] adapter that ASCII85 encodes bytes as they are written, for use with the
+/// `ASCII85Decode` filter.
+///
+/// Bytes are encoded in groups of four, so at most three bytes are held back between writes.
+/// Output is wrapped with `\n` at 80 columns unless changed with
+/// [`line_width`](Self::line_width); the newlines are part of the stream data and count towards
+/// its `/Length`.
+///
+/// Encoded output that the underlying writer has not accepted yet, for example after a
+/// `WouldBlock` error, is kept and written first by the next call, so a failed write can be
+/// retried.
+///
+/// [`finish`](Self::finish) must be called to encode the final partial group and write the `~>`
+/// end-of-data marker; dropping the writer without calling it leaves the output incomplete.
+#[derive(Debug)]
+pub struct Ascii85Writer<W: Write> {
+    inner: W,
+    group: [u8; 4],
+    len: usize,
+    line_width: usize,
+    column: usize,
+    pending: Vec<u8>,
+}
+
+impl<W: Write> Ascii85Writer<W> {
+    /// Creates a new encoder writing to `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            group: [0; 4],
+            len: 0,
+            line_width: ASCII85_LINE_WIDTH,
+            column: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Sets the column at which output is wrapped. A width of `0` disables wrapping.
+    pub fn line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    /// Encodes any remaining bytes, writes the end-of-data marker and returns the underlying
+    /// writer.
+    pub fn finish(mut self) -> std::io::Result<W> {
+        if self.len > 0 {
+            self.group[self.len..].fill(0);
+            let encoded = encode_group(self.group);
+            self.push_wrapped(&encoded[..self.len + 1]);
+            self.len = 0;
+        }
+        // keep the end-of-data marker on one line
+        if self.line_width > 0 && self.column > 0 && self.column + 2 > self.line_width {
+            self.pending.push(b'\n');
+        }
+        self.pending.extend_from_slice(b"~>");
+        self.write_pending()?;
+        Ok(self.inner)
+    }
+
+    /// Encodes the current group into the pending output.
+    fn push_group(&mut self) {
+        if self.group == [0; 4] {
+            self.push_wrapped(b"z");
+        } else {
+            self.push_wrapped(&encode_group(self.group));
+        }
+        self.len = 0;
+    }
+
+    fn push_wrapped(&mut self, mut encoded: &[u8]) {
+        if self.line_width == 0 {
+            self.pending.extend_from_slice(encoded);
+            return;
+        }
+        while !encoded.is_empty() {
+            if self.column == self.line_width {
+                self.pending.push(b'\n');
+                self.column = 0;
+            }
+            let n = encoded.len().min(self.line_width - self.column);
+            self.pending.extend_from_slice(&encoded[..n]);
+            self.column += n;
+            encoded = &encoded[n..];
+        }
+    }
+
+    /// Writes pending output to the underlying writer, keeping whatever it did not accept.
+    fn write_pending(&mut self) -> std::io::Result<()> {
+        let mut written = 0;
+        let result = loop {
+            if written == self.pending.len() {
+                break Ok(());
+            }
+            match self.inner.write(&self.pending[written..]) {
+                Ok(0) => break Err(std::io::ErrorKind::WriteZero.into()),
+                Ok(n) => written += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => break Err(e),
+            }
+        };
+        self.pending.drain(..written);
+        result
+    }
+}
+
+impl<W: Write> Write for Ascii85Writer<W> {
+    /// Encodes `buf`, returning an error without consuming any bytes if earlier output could not
+    /// be written. Once bytes are accepted, a failure to write their encoding is reported by the
+    /// next call instead.
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.write_pending()?;
+        for &byte in buf {
+            self.group[self.len] = byte;
+            self.len += 1;
+            if self.len == 4 {
+                self.push_group();
+            }
+        }
+        // the bytes are accepted either way; anything left over is written by the next call
+        let _ = self.write_pending();
+        Ok(buf.len())
+    }
+
+    /// Writes pending output and flushes the underlying writer. A partial group is held back
+    /// until more bytes are written or the encoder is finished.
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.write_pending()?;
+        self.inner.flush()
+    }
+}
+
+/// Encodes four bytes as five base-85 digits.
+fn encode_group(group: [u8; 4]) -> [u8; 5] {
+    let mut value = u32::from_be_bytes(group);
+    let mut encoded = [0; 5];
+    for digit in encoded.iter_mut().rev() {
+        *digit = (value % 85) as u8 + b'!';
+        value /= 85;
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::CountingWriter;
+
+    fn encode(data: &[u8]) -> String {
+        let mut writer = Ascii85Writer::new(Vec::new()).line_width(0);
+        writer.write_all(data).unwrap();
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_ascii85_writer() {
+        assert_eq!(encode(b""), "~>");
+        assert_eq!(encode(b"Man "), "9jqo^~>");
+        assert_eq!(encode(b"sure."), "F*2M7/c~>");
+        assert_eq!(encode(b"Hello, world!"), "87cURD_*#TDfTZ)+T~>");
+        assert_eq!(encode(&[0xff; 4]), "s8W-!~>");
+        assert_eq!(encode(&[0; 4]), "z~>");
+        // a partial group of zeros is not abbreviated
+        assert_eq!(encode(&[0; 5]), "z!!~>");
+    }
+
+    #[test]
+    fn test_ascii85_writer_chunks() {
+        let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut writer = Ascii85Writer::new(Vec::new()).line_width(0);
+        let mut rest = data.as_slice();
+        for size in [1, 3, 5, 7, 11].into_iter().cycle() {
+            if rest.is_empty() {
+                break;
+            }
+            let (chunk, remaining) = rest.split_at(size.min(rest.len()));
+            writer.write_all(chunk).unwrap();
+            rest = remaining;
+        }
+        let chunked = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(chunked, encode(&data));
+        assert_eq!(chunked.len(), 1250 + 2);
+    }
+
+    #[test]
+    fn test_ascii85_writer_line_width() {
+        let data: Vec<u8> = (0..=255).cycle().take(1000).collect();
+        let mut writer = Ascii85Writer::new(CountingWriter::new(Vec::new()));
+        writer.write_all(&data).unwrap();
+        let counter = writer.finish().unwrap();
+        let length = counter.bytes_written();
+        let wrapped = String::from_utf8(counter.into_inner()).unwrap();
+        assert_eq!(length, wrapped.len());
+        assert!(wrapped.lines().all(|line| line.len() <= ASCII85_LINE_WIDTH));
+        assert_eq!(wrapped.lines().next().unwrap().len(), ASCII85_LINE_WIDTH);
+        assert_eq!(wrapped.replace('\n', ""), encode(&data));
+        // 1250 encoded characters fill 15 full lines and leave 50 (plus ~>) on the last
+        assert_eq!(wrapped.lines().count(), 16);
+        assert_eq!(length, 1250 + 15 + 2);
+    }
+
+    #[test]
+    fn test_ascii85_writer_eod_not_split() {
+        let mut writer = Ascii85Writer::new(Vec::new()).line_width(5);
+        writer.write_all(b"Man Man ").unwrap();
+        let wrapped = String::from_utf8(writer.finish().unwrap()).unwrap();
+        assert_eq!(wrapped, "9jqo^\n9jqo^\n~>");
+    }
+
+    /// Writer that fails with `WouldBlock` on the given calls.
+    struct FlakyWriter {
+        data: Vec<u8>,
+        calls: usize,
+        failures: Vec<usize>,
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.calls += 1;
+            if self.failures.contains(&self.calls) {
+                return Err(std::io::ErrorKind::WouldBlock.into());
+            }
+            self.data.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_ascii85_writer_retry() {
+        let flaky = FlakyWriter {
+            data: Vec::new(),
+            calls: 0,
+            failures: vec![1, 2, 4, 5],
+        };
+        let mut writer = Ascii85Writer::new(flaky).line_width(0);
+        // the first encoding is not accepted by the inner writer, but the input is
+        assert_eq!(writer.write(b"abcd").unwrap(), 4);
+        // the pending output still fails, so nothing is consumed
+        let err = writer.write(b"efgh").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WouldBlock);
+        // retrying does not panic and writes both groups
+        assert_eq!(writer.write(b"efgh").unwrap(), 4);
+        assert!(writer.flush().is_err());
+        writer.flush().unwrap();
+        writer.write_all(b"ijk").unwrap();
+        let flaky = writer.finish().unwrap();
+        assert_eq!(
+            String::from_utf8(flaky.data).unwrap(),
+            encode(b"abcdefghijk")
+        );
+    }
+}
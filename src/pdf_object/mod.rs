@@ -0,0 +1,8 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Encoders for PDF object data.
+
+mod ascii85_writer;
+
+pub use ascii85_writer::Ascii85Writer;
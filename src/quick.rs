@@ -0,0 +1,401 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+//! Quick helpers for producing simple PDF documents in a single call.
+
+use crate::helpers::CountingWriter;
+use std::io::Write;
+
+/// A4 media box in points.
+const PAGE_WIDTH: f64 = 595.27559;
+const PAGE_HEIGHT: f64 = 841.88977;
+
+/// Page margin in points.
+const MARGIN: f64 = 72.0;
+
+/// Heading font size and leading in points.
+const HEADING_SIZE: f64 = 18.0;
+const HEADING_LEADING: f64 = 22.0;
+
+/// Paragraph font size and leading in points.
+const TEXT_SIZE: f64 = 11.0;
+const TEXT_LEADING: f64 = 14.0;
+
+/// Courier glyphs are all 600/1000 em wide, which makes wrapping exact without font metrics.
+const COURIER_WIDTH: f64 = 0.6;
+
+/// Helvetica-Bold advance widths in 1/1000 em for the printable ASCII characters, from its AFM.
+#[rustfmt::skip]
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, // ' '..='/'
+    556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, // '0'..='?'
+    975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778, // '@'..='O'
+    667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333, 278, 333, 584, 556, // 'P'..='_'
+    333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556, 278, 889, 611, 611, // '`'..='o'
+    611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,      // 'p'..='~'
+];
+
+/// Creates an A4 document with a heading and the given paragraphs, returning the PDF bytes.
+///
+/// The heading is set in Helvetica-Bold and paragraphs in Courier, both wrapped at the page
+/// margins. Additional pages are added when the text does not fit on one page. Characters outside
+/// of Latin-1 are replaced with `?` on the page; the document title metadata keeps them.
+///
+/// ```
+/// let pdf = easy_pdf::quick::simple_document("Hello", &["Hello, world!"]).unwrap();
+/// assert!(pdf.starts_with(b"%PDF-"));
+/// ```
+pub fn simple_document(
+    title: &str,
+    paragraphs: &[&str],
+) -> Result<Vec<u8>, Box<dyn std::error::Error>> {
+    let pages = layout_pages(title, paragraphs);
+
+    // object ids: 1 catalog, 2 pages, 3 info, 4 heading font, 5 text font, then page/content pairs
+    let page_id = |i: usize| 6 + i * 2;
+    let size = 6 + pages.len() * 2;
+
+    let mut writer = CountingWriter::new(Vec::new());
+    let mut offsets = vec![0; size];
+    writer.write_all(b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n")?;
+
+    offsets[1] = writer.bytes_written();
+    writeln!(writer, "1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj")?;
+
+    offsets[2] = writer.bytes_written();
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", page_id(i)))
+        .collect();
+    writeln!(
+        writer,
+        "2 0 obj\n<< /Type /Pages /Kids [{}] /Count {} >>\nendobj",
+        kids.join(" "),
+        pages.len()
+    )?;
+
+    offsets[3] = writer.bytes_written();
+    writeln!(
+        writer,
+        "3 0 obj\n<< /Title {} /Producer (easy-pdf) >>\nendobj",
+        text_string(title)
+    )?;
+
+    for (id, font) in [(4, "Helvetica-Bold"), (5, "Courier")] {
+        offsets[id] = writer.bytes_written();
+        writeln!(
+            writer,
+            "{} 0 obj\n<< /Type /Font /Subtype /Type1 /BaseFont /{} \
+             /Encoding /WinAnsiEncoding >>\nendobj",
+            id, font
+        )?;
+    }
+
+    for (i, content) in pages.iter().enumerate() {
+        let id = page_id(i);
+        offsets[id] = writer.bytes_written();
+        writeln!(
+            writer,
+            "{} 0 obj\n<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents {} 0 R >>\nendobj",
+            id,
+            format_number(PAGE_WIDTH),
+            format_number(PAGE_HEIGHT),
+            id + 1
+        )?;
+        offsets[id + 1] = writer.bytes_written();
+        writeln!(
+            writer,
+            "{} 0 obj\n<< /Length {} >>\nstream",
+            id + 1,
+            content.len()
+        )?;
+        writer.write_all(content)?;
+        writer.write_all(b"\nendstream\nendobj\n")?;
+    }
+
+    let startxref = writer.bytes_written();
+    writeln!(writer, "xref\n0 {}\n0000000000 65535 f ", size)?;
+    for offset in &offsets[1..] {
+        writeln!(writer, "{:010} 00000 n ", offset)?;
+    }
+    writeln!(
+        writer,
+        "trailer\n<< /Size {} /Root 1 0 R /Info 3 0 R >>\nstartxref\n{}\n%%EOF",
+        size, startxref
+    )?;
+
+    Ok(writer.into_inner())
+}
+
+/// Lays out the heading and paragraphs, returning the content stream of each page.
+fn layout_pages(title: &str, paragraphs: &[&str]) -> Vec<Vec<u8>> {
+    let top = PAGE_HEIGHT - MARGIN;
+    let max_width = PAGE_WIDTH - 2.0 * MARGIN;
+
+    let mut pages = Vec::new();
+    let mut content = String::new();
+    let mut y = top;
+    let mut show = |content: &mut String, y: &mut f64, font: &str, size: f64, line: &str| {
+        if *y - size < MARGIN {
+            // a page with nothing on it yet keeps the line, however little room is left
+            if !content.is_empty() {
+                pages.push(std::mem::take(content).into_bytes());
+            }
+            *y = top;
+        }
+        content.push_str(&format!(
+            "BT\n/{} {} Tf\n{} {} Td\n({}) Tj\nET\n",
+            font,
+            format_number(size),
+            format_number(MARGIN),
+            format_number(*y - size),
+            escape_string(line)
+        ));
+    };
+
+    let heading_advance = |c| helvetica_bold_width(c) * HEADING_SIZE;
+    let heading = wrap(title, max_width, heading_advance);
+    for line in &heading {
+        show(&mut content, &mut y, "F1", HEADING_SIZE, line);
+        y -= HEADING_LEADING;
+    }
+    if !heading.is_empty() {
+        y -= TEXT_LEADING;
+    }
+
+    // empty paragraphs have no lines and take no space
+    let text_advance = |_| COURIER_WIDTH * TEXT_SIZE;
+    for paragraph in paragraphs {
+        let lines = wrap(paragraph, max_width, text_advance);
+        for line in &lines {
+            show(&mut content, &mut y, "F2", TEXT_SIZE, line);
+            y -= TEXT_LEADING;
+        }
+        if !lines.is_empty() {
+            y -= TEXT_LEADING;
+        }
+    }
+    pages.push(content.into_bytes());
+    pages
+}
+
+/// Returns the Helvetica-Bold advance width of `c` in em.
+///
+/// Characters without an entry in the table are assumed to be a full em wide, so wrapping errs
+/// on the side of shorter lines.
+fn helvetica_bold_width(c: char) -> f64 {
+    match c {
+        ' '..='~' => HELVETICA_BOLD_WIDTHS[c as usize - ' ' as usize] as f64 / 1000.0,
+        _ => 1.0,
+    }
+}
+
+/// Greedily wraps `text` on whitespace into lines no wider than `max_width`, using `advance` to
+/// measure each character.
+///
+/// Words wider than a line are split. Every line holds at least one character, so wrapping always
+/// makes progress even when a single character is wider than `max_width`.
+fn wrap(text: &str, max_width: f64, advance: impl Fn(char) -> f64) -> Vec<String> {
+    let width = |s: &str| s.chars().map(&advance).sum::<f64>();
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word = word;
+        while !word.is_empty() && width(word) > max_width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            let mut split = word.chars().next().map_or(0, char::len_utf8);
+            let mut used = 0.0;
+            for (i, c) in word.char_indices() {
+                used += advance(c);
+                if used > max_width {
+                    break;
+                }
+                split = i + c.len_utf8();
+            }
+            lines.push(word[..split].to_string());
+            word = &word[split..];
+        }
+        if !line.is_empty() && width(&line) + advance(' ') + width(word) > max_width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(word);
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Formats `value` as a PDF number rounded to five decimal places, without trailing zeros.
+fn format_number(value: f64) -> String {
+    let formatted = format!("{:.5}", value);
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+    if formatted == "-0" {
+        "0".to_string()
+    } else {
+        formatted.to_string()
+    }
+}
+
+/// Encodes `text` as a PDF text string, written as UTF-16BE with a byte order mark in hex form.
+///
+/// Text strings outside of content streams, like `/Title`, are read as PDFDocEncoding when they
+/// lack the byte order mark, which differs from WinAnsiEncoding for some Latin-1 characters.
+fn text_string(text: &str) -> String {
+    let mut encoded = String::from("<FEFF");
+    for unit in text.encode_utf16() {
+        encoded.push_str(&format!("{:04X}", unit));
+    }
+    encoded.push('>');
+    encoded
+}
+
+/// Escapes `text` for use in a PDF literal string.
+///
+/// Non-ASCII Latin-1 characters are written as octal escapes, which map to the same characters
+/// in WinAnsiEncoding. Other characters are replaced with `?`.
+fn escape_string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' '..='~' => escaped.push(c),
+            '\u{a0}'..='\u{ff}' => escaped.push_str(&format!("\\{:03o}", c as u32)),
+            _ => escaped.push('?'),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_document() {
+        let pdf = simple_document(
+            "Hello (World)",
+            &["The quick brown fox jumps over the lazy dog."],
+        )
+        .unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(text.trim_end().ends_with("%%EOF"));
+        assert!(text.contains("/F1 18 Tf\n72 751.88977 Td\n(Hello \\(World\\)) Tj"));
+        assert!(text.contains("/MediaBox [0 0 595.27559 841.88977]"));
+        assert!(text.contains("/Title <FEFF00480065006C006C006F002000280057006F0072006C00640029>"));
+        assert!(text.contains("(The quick brown fox jumps over the lazy dog.) Tj"));
+        assert!(text.contains("/Count 1"));
+    }
+
+    #[test]
+    fn test_simple_document_xref() {
+        let paragraph = "lorem ipsum ".repeat(400);
+        let pdf = simple_document("Long", &[&paragraph]).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Count 2"));
+
+        let startxref: usize = text
+            .rsplit("startxref\n")
+            .next()
+            .unwrap()
+            .lines()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(pdf[startxref..].starts_with(b"xref\n"));
+        let entries = text[startxref..]
+            .lines()
+            .skip(3)
+            .take_while(|l| l.ends_with(" n "));
+        for (i, entry) in entries.enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(format!("{} 0 obj", i + 1).as_bytes()));
+        }
+    }
+
+    #[test]
+    fn test_simple_document_long_title() {
+        let title = "A heading that is far too long to fit on a single line of an A4 page";
+        let pdf = simple_document(title, &[]).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        let lines: Vec<&str> = text
+            .lines()
+            .filter(|l| l.ends_with(") Tj"))
+            .map(|l| &l[1..l.len() - 4])
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines.join(" "), title);
+        for line in lines {
+            let width: f64 = line.chars().map(helvetica_bold_width).sum::<f64>() * HEADING_SIZE;
+            assert!(width <= PAGE_WIDTH - 2.0 * MARGIN);
+        }
+    }
+
+    #[test]
+    fn test_simple_document_empty_paragraphs() {
+        let mut paragraphs = vec![""; 100];
+        paragraphs.push("x");
+        let pdf = simple_document("", &paragraphs).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Count 1"));
+        assert!(!text.contains("/Length 0"));
+        assert!(text.contains("/F2 11 Tf\n72 758.88977 Td\n(x) Tj"));
+
+        // blank pages are not produced when a page fills up either
+        let pdf = simple_document("Title", &["line"; 60]).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("/Count 3"));
+        assert!(!text.contains("/Length 0"));
+    }
+
+    #[test]
+    fn test_wrap() {
+        let advance = |_| 1.0;
+        assert_eq!(wrap("aa bb cc", 5.0, advance), vec!["aa bb", "cc"]);
+        assert_eq!(wrap("  aa\nbb  ", 10.0, advance), vec!["aa bb"]);
+        assert_eq!(wrap("abcdefg h", 3.0, advance), vec!["abc", "def", "g h"]);
+        assert!(wrap("", 10.0, advance).is_empty());
+        // a line always holds at least one character
+        assert_eq!(wrap("ab", 0.0, advance), vec!["a", "b"]);
+        assert_eq!(wrap("ab", -1.0, advance), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_helvetica_bold_width() {
+        assert_eq!(helvetica_bold_width(' '), 0.278);
+        assert_eq!(helvetica_bold_width('W'), 0.944);
+        assert_eq!(helvetica_bold_width('~'), 0.584);
+        assert_eq!(helvetica_bold_width('\u{e9}'), 1.0);
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(72.0), "72");
+        assert_eq!(format_number(841.88977 - 72.0 - 18.0), "751.88977");
+        assert_eq!(format_number(0.1 + 0.2), "0.3");
+        assert_eq!(format_number(-0.000001), "0");
+    }
+
+    #[test]
+    fn test_text_string() {
+        assert_eq!(text_string(""), "<FEFF>");
+        assert_eq!(text_string("a\u{a0}\u{ad}"), "<FEFF006100A000AD>");
+        assert_eq!(text_string("\u{1f600}"), "<FEFFD83DDE00>");
+    }
+
+    #[test]
+    fn test_escape_string() {
+        assert_eq!(escape_string("a(b)c\\"), "a\\(b\\)c\\\\");
+        assert_eq!(escape_string("caf\u{e9} \u{65e5}"), "caf\\351 ?");
+    }
+}
@@ -0,0 +1,19 @@
+// SPDX-FileCopyrightText: 2025 Warner Zee <warner@zoynk.com>
+// SPDX-License-Identifier: MIT OR Apache-2.0
+
+use easy_pdf::quick::simple_document;
+
+#[test]
+fn test_simple_document() {
+    let pdf = simple_document(
+        "Quarterly Report",
+        &["Revenue grew in every region.", "Costs were flat."],
+    )
+    .unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(pdf.starts_with(b"%PDF-"));
+    assert!(text.trim_end().ends_with("%%EOF"));
+    assert!(text.contains("BT\n/F1 18 Tf\n72 751.88977 Td\n(Quarterly Report) Tj\nET\n"));
+    assert!(text.contains("(Revenue grew in every region.) Tj"));
+    assert!(text.contains("(Costs were flat.) Tj"));
+}